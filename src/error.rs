@@ -1,5 +1,6 @@
 use std::io;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use snafu::Snafu;
 
@@ -31,4 +32,12 @@ pub enum Error {
     /// Failed to open/create the lock file.
     #[snafu(display("Opening lock file {path:?} failed: {source}"))]
     OpenLockFile { path: PathBuf, source: io::Error },
+
+    /// A bounded-wait lock attempt did not succeed before its deadline.
+    #[snafu(display("Acquiring {mode} lock on {path:?} timed out after {waited:?}"))]
+    Timeout { path: PathBuf, mode: &'static str, waited: Duration },
+
+    /// A non-blocking lock attempt found the lock already held elsewhere.
+    #[snafu(display("Acquiring {mode} lock on {path:?} would block"))]
+    WouldBlock { path: PathBuf, mode: &'static str },
 }
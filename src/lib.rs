@@ -4,7 +4,10 @@
 //! - Each test: `lock_shared()` and hold the guard for the test duration.
 //! - One finalizer: take `lock_exclusive()` *after* readers drop and perform teardown.
 
-#![forbid(unsafe_code)]
+// `unsafe` is denied (not forbidden) so the isolated `windows_mutex` FFI
+// module below can opt back in locally; the rest of the crate stays
+// `unsafe`-free.
+#![deny(unsafe_code)]
 
 #[cfg(all(feature = "async", feature = "blocking"))]
 compile_error!("\"async\" and \"blocking\" features cannot be enabled at the same time.");
@@ -12,8 +15,15 @@ compile_error!("\"async\" and \"blocking\" features cannot be enabled at the sam
 compile_error!("Enable exactly one of: feature \"async\" or feature \"blocking\".");
 
 mod error;
+#[cfg(windows)]
+mod windows_mutex;
+use std::collections::HashMap;
 use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
 use std::{env, io};
 
 use snafu::ResultExt; // for .context(...)
@@ -22,25 +32,215 @@ pub use crate::error::{Error, Result};
 
 // ============================ Public API types ============================
 
-/// Guard holding a shared/exclusive OS file lock (drops = releases the lock).
+/// Which mode a [`LockGuard`] currently holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Shared,
+    Exclusive,
+}
+
+/// What a [`LockGuard`] is actually holding, depending on the backend its
+/// `XProcessLock` was created with.
 #[derive(Debug)]
-pub struct LockGuard(#[allow(dead_code)] File);
+enum Held {
+    File(File),
+    #[cfg(windows)]
+    NamedMutex(windows_mutex::NamedMutex),
+}
+
+/// A guard's id and a handle back to its `XProcessLock`'s shared-reader
+/// registry, stashed so the guard can remove its own entry on
+/// upgrade/drop without holding a reference to the lock itself.
+type SharedReaderEntry = (u64, Arc<Mutex<HashMap<u64, SystemTime>>>);
+
+/// Guard holding an OS-level lock (drops = releases the lock).
+#[derive(Debug)]
+pub struct LockGuard {
+    held: Held,
+    path: PathBuf,
+    state: State,
+    /// This guard's entry in its `XProcessLock`'s shared-reader registry, if
+    /// it holds a shared lock acquired through `lock_shared`,
+    /// `try_lock_shared` or `lock_shared_timeout`.
+    shared_reader: Option<SharedReaderEntry>,
+}
 
 impl LockGuard {
     /// Convenience: explicitly release the lock.
     pub fn unlock(self) {
         drop(self);
     }
+
+    /// Borrow the locked file, e.g. to write PID/hostname diagnostics while
+    /// the lock is held. `None` if this guard was acquired through the
+    /// named-mutex backend, which has no backing file.
+    pub fn file(&self) -> Option<&File> {
+        match &self.held {
+            Held::File(f) => Some(f),
+            #[cfg(windows)]
+            Held::NamedMutex(_) => None,
+        }
+    }
+
+    /// Mutably borrow the locked file. `None` under the same condition as
+    /// [`Self::file`].
+    pub fn file_mut(&mut self) -> Option<&mut File> {
+        match &mut self.held {
+            Held::File(f) => Some(f),
+            #[cfg(windows)]
+            Held::NamedMutex(_) => None,
+        }
+    }
+
+    /// The path of the underlying lock file, or the named-mutex object name
+    /// when using that backend.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Which mode this guard currently holds.
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// Escalate a **shared** lock to **exclusive** in place, blocking until
+    /// any other shared holders release. Since flock-style lock conversion
+    /// isn't atomic on all platforms, this re-invokes the lock call on the
+    /// same file handle rather than dropping and re-acquiring. A no-op on
+    /// the named-mutex backend, which has no shared mode to begin with.
+    ///
+    /// On failure, returns the original guard alongside the error rather
+    /// than dropping it, so a reader that fails to escalate keeps the shared
+    /// lock it already validly held instead of silently losing it.
+    pub fn upgrade(mut self) -> std::result::Result<LockGuard, (LockGuard, Error)> {
+        let acquired = match &self.held {
+            Held::File(f) => f.lock().context(error::AcquireLockSnafu { path: self.path.clone(), mode: "exclusive" }),
+            #[cfg(windows)]
+            Held::NamedMutex(_) => Ok(()),
+        };
+        if let Err(source) = acquired {
+            return Err((self, source));
+        }
+        self.state = State::Exclusive;
+        // No longer a shared reader: drop our registry entry up front instead
+        // of waiting for a later Drop to do it under the new exclusive hold.
+        if let Some((id, readers)) = self.shared_reader.take() {
+            if let Ok(mut readers) = readers.lock() {
+                readers.remove(&id);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Relax an **exclusive** lock to **shared** in place. A no-op on the
+    /// named-mutex backend, which has no shared mode to begin with.
+    ///
+    /// On failure, returns the original guard alongside the error rather
+    /// than dropping it, matching [`Self::upgrade`].
+    pub fn downgrade(mut self) -> std::result::Result<LockGuard, (LockGuard, Error)> {
+        let acquired = match &self.held {
+            Held::File(f) => f.lock_shared().context(error::AcquireLockSnafu { path: self.path.clone(), mode: "shared" }),
+            #[cfg(windows)]
+            Held::NamedMutex(_) => Ok(()),
+        };
+        if let Err(source) = acquired {
+            return Err((self, source));
+        }
+        self.state = State::Shared;
+        Ok(self)
+    }
 }
 
-/// Named, cross‑process lock. The `name` becomes `<base>/<sanitized>.lock`.
+impl Read for LockGuard {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match &mut self.held {
+            Held::File(f) => f.read(buf),
+            #[cfg(windows)]
+            Held::NamedMutex(_) => Err(io::Error::new(io::ErrorKind::Unsupported, "not backed by a file")),
+        }
+    }
+}
+
+impl Write for LockGuard {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match &mut self.held {
+            Held::File(f) => f.write(buf),
+            #[cfg(windows)]
+            Held::NamedMutex(_) => Err(io::Error::new(io::ErrorKind::Unsupported, "not backed by a file")),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.held {
+            Held::File(f) => f.flush(),
+            #[cfg(windows)]
+            Held::NamedMutex(_) => Ok(()),
+        }
+    }
+}
+
+impl Seek for LockGuard {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match &mut self.held {
+            Held::File(f) => f.seek(pos),
+            #[cfg(windows)]
+            Held::NamedMutex(_) => Err(io::Error::new(io::ErrorKind::Unsupported, "not backed by a file")),
+        }
+    }
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        if let Some((id, readers)) = &self.shared_reader {
+            if let Ok(mut readers) = readers.lock() {
+                readers.remove(id);
+            }
+        }
+    }
+}
+
+/// Next id handed out to a shared-lock registry entry.
+static NEXT_GUARD_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Which OS primitive an `XProcessLock` acquires under the hood.
+#[derive(Debug, Clone)]
+enum Backend {
+    /// The default: an advisory `flock`-style lock on a regular file.
+    FileLock(PathBuf),
+    /// Windows only: a named kernel mutex, guaranteed to release promptly
+    /// if the owning process exits or crashes.
+    #[cfg(windows)]
+    NamedMutex(String),
+}
+
+impl Backend {
+    /// A path-shaped value to report in diagnostics/errors, real for
+    /// [`Backend::FileLock`] and the mutex's kernel object name otherwise.
+    fn diagnostic_path(&self) -> PathBuf {
+        match self {
+            Backend::FileLock(path) => path.clone(),
+            #[cfg(windows)]
+            Backend::NamedMutex(object_name) => PathBuf::from(object_name),
+        }
+    }
+}
+
+/// Named, cross‑process lock. The `name` becomes `<base>/<sanitized>.lock`
+/// by default, or a sanitized Win32 kernel object name when created via
+/// [`XProcessLock::create_named_mutex`].
 #[derive(Debug)]
 pub struct XProcessLock {
-    lock_file: PathBuf,
+    backend: Backend,
+    /// Acquire-time of every outstanding shared lock taken through one of the
+    /// `lock_shared*`/`try_lock_shared` methods, keyed by guard id, for
+    /// stale-reader diagnostics (see [`Self::oldest_shared_lock`]). A guard
+    /// that becomes shared through [`LockGuard::downgrade`] is not recorded.
+    shared_readers: Arc<Mutex<HashMap<u64, SystemTime>>>,
 }
 
 impl XProcessLock {
-    /// Create a lock scope identified by `name`.
+    /// Create a lock scope identified by `name`, using the default
+    /// file-lock backend.
     pub fn create(name: impl Into<String>) -> Result<Self> {
         let name = name.into();
         if name.trim().is_empty() {
@@ -48,7 +248,53 @@ impl XProcessLock {
         }
         let name = format!("{}.lock", sanitize(&name));
         let lock_file = default_base_dir().join(name);
-        Ok(Self { lock_file })
+        Ok(Self { backend: Backend::FileLock(lock_file), shared_readers: Arc::new(Mutex::new(HashMap::new())) })
+    }
+
+    /// Create a lock scope identified by `name`, backed by a Windows named
+    /// mutex instead of the default advisory file lock. Unlike `LockFile`,
+    /// Windows doesn't clean up advisory file locks promptly when the owning
+    /// process exits or crashes; a named mutex is a kernel object that's
+    /// released the instant the process goes away, so use this backend when
+    /// that guarantee matters more than portability.
+    ///
+    /// `prefix` selects the kernel object's namespace: `"Global\\"` shares
+    /// the lock across all sessions on the machine, `"Local\\"` (the
+    /// default when `None`) scopes it to the current session.
+    ///
+    /// The named-mutex backend has no shared/read mode: `lock_shared`
+    /// behaves like `lock_exclusive`, and [`LockGuard::upgrade`]/
+    /// [`LockGuard::downgrade`] are no-ops.
+    #[cfg(windows)]
+    pub fn create_named_mutex(name: impl Into<String>, prefix: Option<&str>) -> Result<Self> {
+        let name = name.into();
+        if name.trim().is_empty() {
+            return error::EmptyNameSnafu.fail();
+        }
+        let prefix = prefix.unwrap_or("Local\\");
+        let object_name = format!("{prefix}{}", sanitize(&name));
+        Ok(Self {
+            backend: Backend::NamedMutex(object_name),
+            shared_readers: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    /// The acquire timestamp of the oldest currently-outstanding shared lock
+    /// in this lock's registry, or `None` if the registry is empty. Only
+    /// shared locks taken through `lock_shared`, `try_lock_shared` or
+    /// `lock_shared_timeout` are counted; a guard that becomes shared through
+    /// [`LockGuard::downgrade`] is not.
+    /// A would-be exclusive writer can use this to decide whether readers
+    /// have monopolized the lock longer than expected before teardown blocks.
+    pub fn oldest_shared_lock(&self) -> Option<SystemTime> {
+        self.shared_readers.lock().unwrap().values().min().copied()
+    }
+
+    /// Record a newly granted shared lock and return its registry entry.
+    fn track_shared_reader(&self) -> SharedReaderEntry {
+        let id = NEXT_GUARD_ID.fetch_add(1, Ordering::Relaxed);
+        self.shared_readers.lock().unwrap().insert(id, SystemTime::now());
+        (id, self.shared_readers.clone())
     }
 }
 
@@ -58,14 +304,58 @@ impl XProcessLock {
 impl XProcessLock {
     /// Take an **exclusive** lock (blocks until all shared holders release).
     pub async fn lock_exclusive(&self) -> Result<LockGuard> {
-        let guard = open_locked_async(self.lock_file.clone(), LockMode::Exclusive).await?;
-        Ok(LockGuard(guard))
+        let held = acquire_async(self.backend.clone(), LockMode::Exclusive, false).await?;
+        Ok(LockGuard { held, path: self.backend.diagnostic_path(), state: State::Exclusive, shared_reader: None })
     }
 
     /// Take a **shared** (read) lock for the duration of your test/work.
+    /// On the named-mutex backend this behaves like `lock_exclusive`.
     pub async fn lock_shared(&self) -> Result<LockGuard> {
-        let guard = open_locked_async(self.lock_file.clone(), LockMode::Shared).await?;
-        Ok(LockGuard(guard))
+        let held = acquire_async(self.backend.clone(), LockMode::Shared, false).await?;
+        Ok(LockGuard {
+            held,
+            path: self.backend.diagnostic_path(),
+            state: State::Shared,
+            shared_reader: Some(self.track_shared_reader()),
+        })
+    }
+
+    /// Attempt an **exclusive** lock without waiting; errors with
+    /// [`Error::WouldBlock`] if another holder currently has it.
+    pub async fn try_lock_exclusive(&self) -> Result<LockGuard> {
+        let held = acquire_async(self.backend.clone(), LockMode::Exclusive, true).await?;
+        Ok(LockGuard { held, path: self.backend.diagnostic_path(), state: State::Exclusive, shared_reader: None })
+    }
+
+    /// Attempt a **shared** lock without waiting; errors with
+    /// [`Error::WouldBlock`] if an exclusive holder currently has it.
+    pub async fn try_lock_shared(&self) -> Result<LockGuard> {
+        let held = acquire_async(self.backend.clone(), LockMode::Shared, true).await?;
+        Ok(LockGuard {
+            held,
+            path: self.backend.diagnostic_path(),
+            state: State::Shared,
+            shared_reader: Some(self.track_shared_reader()),
+        })
+    }
+
+    /// Take an **exclusive** lock, giving up with [`Error::Timeout`] if it
+    /// cannot be acquired within `timeout`.
+    pub async fn lock_exclusive_timeout(&self, timeout: Duration) -> Result<LockGuard> {
+        let held = acquire_timeout_async(self.backend.clone(), LockMode::Exclusive, timeout).await?;
+        Ok(LockGuard { held, path: self.backend.diagnostic_path(), state: State::Exclusive, shared_reader: None })
+    }
+
+    /// Take a **shared** lock, giving up with [`Error::Timeout`] if it cannot
+    /// be acquired within `timeout`.
+    pub async fn lock_shared_timeout(&self, timeout: Duration) -> Result<LockGuard> {
+        let held = acquire_timeout_async(self.backend.clone(), LockMode::Shared, timeout).await?;
+        Ok(LockGuard {
+            held,
+            path: self.backend.diagnostic_path(),
+            state: State::Shared,
+            shared_reader: Some(self.track_shared_reader()),
+        })
     }
 }
 
@@ -75,14 +365,58 @@ impl XProcessLock {
 impl XProcessLock {
     /// Take an **exclusive** lock (blocks until all shared holders release).
     pub fn lock_exclusive(&self) -> Result<LockGuard> {
-        let guard = open_locked(&self.lock_file, LockMode::Exclusive)?;
-        Ok(LockGuard(guard))
+        let held = acquire(&self.backend, LockMode::Exclusive, false)?;
+        Ok(LockGuard { held, path: self.backend.diagnostic_path(), state: State::Exclusive, shared_reader: None })
     }
 
     /// Take a **shared** (read) lock for the duration of your test/work.
+    /// On the named-mutex backend this behaves like `lock_exclusive`.
     pub fn lock_shared(&self) -> Result<LockGuard> {
-        let guard = open_locked(&self.lock_file, LockMode::Shared)?;
-        Ok(LockGuard(guard))
+        let held = acquire(&self.backend, LockMode::Shared, false)?;
+        Ok(LockGuard {
+            held,
+            path: self.backend.diagnostic_path(),
+            state: State::Shared,
+            shared_reader: Some(self.track_shared_reader()),
+        })
+    }
+
+    /// Attempt an **exclusive** lock without waiting; errors with
+    /// [`Error::WouldBlock`] if another holder currently has it.
+    pub fn try_lock_exclusive(&self) -> Result<LockGuard> {
+        let held = acquire(&self.backend, LockMode::Exclusive, true)?;
+        Ok(LockGuard { held, path: self.backend.diagnostic_path(), state: State::Exclusive, shared_reader: None })
+    }
+
+    /// Attempt a **shared** lock without waiting; errors with
+    /// [`Error::WouldBlock`] if an exclusive holder currently has it.
+    pub fn try_lock_shared(&self) -> Result<LockGuard> {
+        let held = acquire(&self.backend, LockMode::Shared, true)?;
+        Ok(LockGuard {
+            held,
+            path: self.backend.diagnostic_path(),
+            state: State::Shared,
+            shared_reader: Some(self.track_shared_reader()),
+        })
+    }
+
+    /// Take an **exclusive** lock, giving up with [`Error::Timeout`] if it
+    /// cannot be acquired within `timeout`.
+    pub fn lock_exclusive_timeout(&self, timeout: Duration) -> Result<LockGuard> {
+        let held = acquire_timeout(&self.backend, LockMode::Exclusive, timeout)?;
+        Ok(LockGuard { held, path: self.backend.diagnostic_path(), state: State::Exclusive, shared_reader: None })
+    }
+
+    /// Take a **shared** lock, giving up with [`Error::Timeout`] if it cannot
+    /// be acquired within `timeout`.
+    pub fn lock_shared_timeout(&self, timeout: Duration) -> Result<LockGuard> {
+        let held = acquire_timeout(&self.backend, LockMode::Shared, timeout)?;
+        Ok(LockGuard {
+            held,
+            path: self.backend.diagnostic_path(),
+            state: State::Shared,
+            shared_reader: Some(self.track_shared_reader()),
+        })
     }
 }
 
@@ -94,12 +428,23 @@ enum LockMode {
     Shared,
 }
 
+impl LockMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            LockMode::Exclusive => "exclusive",
+            LockMode::Shared => "shared",
+        }
+    }
+}
+
 fn default_base_dir() -> PathBuf {
     env::var_os("XPROCESS_LOCK_DIR").map(PathBuf::from).unwrap_or_else(|| env::temp_dir().join("xprocess-lock"))
 }
 
-/// Open (create if needed) and lock the file (blocking).
-fn open_locked(path: &Path, mode: LockMode) -> Result<File> {
+/// Open (create if needed) and lock the file. When `non_blocking` is set, the
+/// lock attempt never waits for other holders and reports [`Error::WouldBlock`]
+/// on contention instead.
+fn open_file_lock(path: &Path, mode: LockMode, non_blocking: bool) -> Result<File> {
     // Ensure directory exists.
     if let Some(dir) = path.parent() {
         std::fs::create_dir_all(dir).context(error::CreateDirSnafu { path: dir.to_path_buf() })?;
@@ -107,26 +452,124 @@ fn open_locked(path: &Path, mode: LockMode) -> Result<File> {
 
     let f = open_lock_file(path).context(error::OpenLockFileSnafu { path: path.to_path_buf() })?;
 
-    match mode {
-        LockMode::Exclusive => {
-            f.lock().context(error::AcquireLockSnafu { path: path.to_path_buf(), mode: "exclusive" })?
+    if non_blocking {
+        let result = match mode {
+            LockMode::Exclusive => f.try_lock(),
+            LockMode::Shared => f.try_lock_shared(),
+        };
+        match result {
+            Ok(()) => {}
+            Err(std::fs::TryLockError::WouldBlock) => {
+                return error::WouldBlockSnafu { path: path.to_path_buf(), mode: mode.as_str() }.fail();
+            }
+            Err(std::fs::TryLockError::Error(source)) => {
+                return Err(source).context(error::AcquireLockSnafu { path: path.to_path_buf(), mode: mode.as_str() });
+            }
         }
-        LockMode::Shared => {
-            f.lock_shared().context(error::AcquireLockSnafu { path: path.to_path_buf(), mode: "shared" })?
+    } else {
+        match mode {
+            LockMode::Exclusive => {
+                f.lock().context(error::AcquireLockSnafu { path: path.to_path_buf(), mode: "exclusive" })?
+            }
+            LockMode::Shared => {
+                f.lock_shared().context(error::AcquireLockSnafu { path: path.to_path_buf(), mode: "shared" })?
+            }
         }
     }
     Ok(f)
 }
 
+/// Acquire whichever OS primitive `backend` wraps, dispatching to the
+/// file-lock or named-mutex implementation as appropriate.
+fn acquire(backend: &Backend, mode: LockMode, non_blocking: bool) -> Result<Held> {
+    match backend {
+        Backend::FileLock(path) => open_file_lock(path, mode, non_blocking).map(Held::File),
+        #[cfg(windows)]
+        Backend::NamedMutex(object_name) => {
+            let timeout = non_blocking.then_some(Duration::ZERO);
+            windows_mutex::NamedMutex::acquire(object_name, timeout)
+                .map(Held::NamedMutex)
+                .map_err(|source| classify_mutex_error(object_name, mode, source))
+        }
+    }
+}
+
 #[cfg(feature = "async")]
-async fn open_locked_async(path: PathBuf, mode: LockMode) -> Result<File> {
+async fn acquire_async(backend: Backend, mode: LockMode, non_blocking: bool) -> Result<Held> {
     use error::JoinBlockingSnafu;
 
-    if let Some(dir) = path.parent() {
-        tokio::fs::create_dir_all(dir).await.context(error::CreateDirSnafu { path: dir.to_path_buf() })?;
+    match &backend {
+        Backend::FileLock(path) => {
+            if let Some(dir) = path.parent() {
+                tokio::fs::create_dir_all(dir).await.context(error::CreateDirSnafu { path: dir.to_path_buf() })?;
+            }
+        }
+        #[cfg(windows)]
+        Backend::NamedMutex(_) => {}
+    }
+    // Run the blocking acquire off the runtime thread.
+    tokio::task::spawn_blocking(move || acquire(&backend, mode, non_blocking)).await.context(JoinBlockingSnafu)?
+}
+
+/// Map a Win32 named-mutex acquisition failure onto the crate's error type,
+/// distinguishing contention ([`Error::WouldBlock`]) from real OS errors.
+#[cfg(windows)]
+fn classify_mutex_error(object_name: &str, mode: LockMode, source: io::Error) -> Error {
+    let path = PathBuf::from(object_name);
+    if source.kind() == io::ErrorKind::WouldBlock {
+        error::WouldBlockSnafu { path, mode: mode.as_str() }.build()
+    } else {
+        error::AcquireLockSnafu { path, mode: mode.as_str(), source }.build()
+    }
+}
+
+/// Starting backoff between non-blocking retry attempts.
+const MIN_BACKOFF: Duration = Duration::from_millis(5);
+/// Upper bound the backoff is capped at, however long the deadline allows.
+const MAX_BACKOFF: Duration = Duration::from_millis(200);
+
+/// Retry a non-blocking lock attempt with capped exponential backoff until
+/// either it succeeds or `timeout` elapses.
+#[cfg(feature = "blocking")]
+fn acquire_timeout(backend: &Backend, mode: LockMode, timeout: Duration) -> Result<Held> {
+    let start = Instant::now();
+    let mut backoff = MIN_BACKOFF;
+    loop {
+        match acquire(backend, mode, true) {
+            Ok(held) => return Ok(held),
+            Err(Error::WouldBlock { .. }) => {
+                let waited = start.elapsed();
+                if waited >= timeout {
+                    return error::TimeoutSnafu { path: backend.diagnostic_path(), mode: mode.as_str(), waited }
+                        .fail();
+                }
+                std::thread::sleep(backoff.min(timeout - waited));
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+async fn acquire_timeout_async(backend: Backend, mode: LockMode, timeout: Duration) -> Result<Held> {
+    let start = Instant::now();
+    let mut backoff = MIN_BACKOFF;
+    loop {
+        match acquire_async(backend.clone(), mode, true).await {
+            Ok(held) => return Ok(held),
+            Err(Error::WouldBlock { .. }) => {
+                let waited = start.elapsed();
+                if waited >= timeout {
+                    return error::TimeoutSnafu { path: backend.diagnostic_path(), mode: mode.as_str(), waited }
+                        .fail();
+                }
+                tokio::time::sleep(backoff.min(timeout - waited)).await;
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+            Err(e) => return Err(e),
+        }
     }
-    // Run the blocking open+lock sequence off the runtime thread.
-    tokio::task::spawn_blocking(move || open_locked(&path, mode)).await.context(JoinBlockingSnafu)?
 }
 
 fn open_lock_file(path: &Path) -> io::Result<File> {
@@ -185,4 +628,124 @@ mod tests {
         assert!(matches!(XProcessLock::create(""), Err(Error::EmptyName)));
         assert!(matches!(XProcessLock::create("   "), Err(Error::EmptyName)));
     }
+
+    /// Point `XPROCESS_LOCK_DIR` at a fresh temp directory unique to this
+    /// test and process, run `f`, then remove the directory.
+    fn with_temp_lock_dir(name: &str, f: impl FnOnce()) {
+        let dir = std::env::temp_dir().join(format!("xprocess-lock-{name}-{}", std::process::id()));
+        temp_env::with_var("XPROCESS_LOCK_DIR", Some(dir.to_str().unwrap()), f);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn try_lock_exclusive_fails_when_already_held() {
+        with_temp_lock_dir("try-exclusive", || {
+            // Two independent `XProcessLock`s pointed at the same name open
+            // independent file descriptions, so they genuinely contend via flock.
+            let holder = XProcessLock::create("try-exclusive-test").unwrap();
+            let contender = XProcessLock::create("try-exclusive-test").unwrap();
+
+            let _guard = holder.lock_exclusive().unwrap();
+            assert!(matches!(contender.try_lock_exclusive(), Err(Error::WouldBlock { .. })));
+        });
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn try_lock_shared_fails_when_exclusively_held() {
+        with_temp_lock_dir("try-shared", || {
+            let holder = XProcessLock::create("try-shared-test").unwrap();
+            let contender = XProcessLock::create("try-shared-test").unwrap();
+
+            let _guard = holder.lock_exclusive().unwrap();
+            assert!(matches!(contender.try_lock_shared(), Err(Error::WouldBlock { .. })));
+        });
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn lock_exclusive_timeout_times_out_when_contended() {
+        with_temp_lock_dir("timeout-exceeded", || {
+            let holder = XProcessLock::create("timeout-exceeded-test").unwrap();
+            let contender = XProcessLock::create("timeout-exceeded-test").unwrap();
+
+            let _guard = holder.lock_exclusive().unwrap();
+            let result = contender.lock_exclusive_timeout(Duration::from_millis(50));
+            assert!(matches!(result, Err(Error::Timeout { .. })));
+        });
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn lock_exclusive_timeout_succeeds_once_holder_drops() {
+        with_temp_lock_dir("timeout-succeeds", || {
+            let holder = XProcessLock::create("timeout-succeeds-test").unwrap();
+            let contender = XProcessLock::create("timeout-succeeds-test").unwrap();
+
+            let guard = holder.lock_exclusive().unwrap();
+            let releaser = std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(50));
+                drop(guard);
+            });
+            let result = contender.lock_exclusive_timeout(Duration::from_secs(2));
+            releaser.join().unwrap();
+            assert!(result.is_ok());
+        });
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn guard_roundtrips_bytes_through_the_locked_file() {
+        with_temp_lock_dir("guard-io", || {
+            let lock = XProcessLock::create("guard-io-test").unwrap();
+
+            {
+                let mut guard = lock.lock_exclusive().unwrap();
+                assert_eq!(guard.path(), lock.backend.diagnostic_path());
+                guard.write_all(b"hello").unwrap();
+                guard.flush().unwrap();
+            }
+
+            {
+                let mut guard = lock.lock_exclusive().unwrap();
+                guard.file_mut().unwrap().seek(SeekFrom::Start(0)).unwrap();
+                let mut buf = String::new();
+                guard.file_mut().unwrap().read_to_string(&mut buf).unwrap();
+                assert_eq!(buf, "hello");
+            }
+        });
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn guard_upgrades_and_downgrades_in_place() {
+        with_temp_lock_dir("guard-state", || {
+            let lock = XProcessLock::create("guard-state-test").unwrap();
+
+            let guard = lock.lock_shared().unwrap();
+            assert_eq!(guard.state(), State::Shared);
+
+            let guard = guard.upgrade().unwrap();
+            assert_eq!(guard.state(), State::Exclusive);
+
+            let guard = guard.downgrade().unwrap();
+            assert_eq!(guard.state(), State::Shared);
+        });
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn oldest_shared_lock_tracks_outstanding_readers() {
+        with_temp_lock_dir("oldest-reader", || {
+            let lock = XProcessLock::create("oldest-reader-test").unwrap();
+            assert!(lock.oldest_shared_lock().is_none());
+
+            let guard = lock.lock_shared().unwrap();
+            assert!(lock.oldest_shared_lock().is_some());
+
+            drop(guard);
+            assert!(lock.oldest_shared_lock().is_none());
+        });
+    }
 }
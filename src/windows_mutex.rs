@@ -0,0 +1,99 @@
+//! Win32 named-mutex FFI, isolated so `unsafe` stays out of the portable core.
+//!
+//! Unlike an advisory file lock, a `CreateMutexW` handle is a kernel object:
+//! Windows releases it the moment the owning process exits or crashes, even
+//! if the process never got a chance to run its own cleanup code. That's the
+//! guarantee [`crate::XProcessLock::create_named_mutex`] trades the
+//! file-lock backend's portability for.
+
+#![allow(unsafe_code)]
+
+use std::ffi::c_void;
+use std::io;
+use std::time::Duration;
+
+type Handle = *mut c_void;
+type Bool = i32;
+type DWord = u32;
+
+const FALSE: Bool = 0;
+const INFINITE: DWord = 0xFFFF_FFFF;
+const WAIT_OBJECT_0: DWord = 0x0000_0000;
+const WAIT_ABANDONED: DWord = 0x0000_0080;
+const WAIT_TIMEOUT: DWord = 0x0000_0102;
+const WAIT_FAILED: DWord = 0xFFFF_FFFF;
+
+extern "system" {
+    fn CreateMutexW(security_attrs: *const c_void, initial_owner: Bool, name: *const u16) -> Handle;
+    fn WaitForSingleObject(handle: Handle, millis: DWord) -> DWord;
+    fn ReleaseMutex(handle: Handle) -> Bool;
+    fn CloseHandle(handle: Handle) -> Bool;
+}
+
+/// An owned, held Win32 named mutex. Releasing and closing the handle on
+/// drop is what gives this backend prompt cross-process cleanup.
+#[derive(Debug)]
+pub(crate) struct NamedMutex {
+    handle: Handle,
+}
+
+// SAFETY: Win32 kernel object handles are explicitly documented as safe to
+// use from any thread, so it's sound to move ownership across threads.
+unsafe impl Send for NamedMutex {}
+
+impl NamedMutex {
+    /// Create (or open an existing) named mutex object and wait to own it.
+    /// `timeout: None` waits indefinitely; `Some(Duration::ZERO)` polls once
+    /// without blocking, reporting contention as `io::ErrorKind::WouldBlock`.
+    pub(crate) fn acquire(object_name: &str, timeout: Option<Duration>) -> io::Result<Self> {
+        let wide: Vec<u16> = object_name.encode_utf16().chain(std::iter::once(0)).collect();
+        // SAFETY: `wide` is a valid, nul-terminated UTF-16 string that outlives this call.
+        let handle = unsafe { CreateMutexW(std::ptr::null(), FALSE, wide.as_ptr()) };
+        if handle.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        let millis = match timeout {
+            None => INFINITE,
+            Some(d) => d.as_millis().min(DWord::MAX as u128) as DWord,
+        };
+        // SAFETY: `handle` was just created above and is a valid mutex handle.
+        match unsafe { WaitForSingleObject(handle, millis) } {
+            // An abandoned mutex (previous owner exited without releasing it) is
+            // still successfully acquired; that's the whole point of this backend.
+            WAIT_OBJECT_0 | WAIT_ABANDONED => Ok(Self { handle }),
+            WAIT_TIMEOUT => {
+                close(handle);
+                Err(io::Error::from(io::ErrorKind::WouldBlock))
+            }
+            WAIT_FAILED => {
+                let err = io::Error::last_os_error();
+                close(handle);
+                Err(err)
+            }
+            other => {
+                close(handle);
+                Err(io::Error::other(format!("WaitForSingleObject returned unexpected status {other:#x}")))
+            }
+        }
+    }
+}
+
+impl Drop for NamedMutex {
+    fn drop(&mut self) {
+        // SAFETY: `self.handle` is a valid, owned mutex handle for the
+        // lifetime of `self`, and we only release/close it once here.
+        unsafe {
+            ReleaseMutex(self.handle);
+        }
+        close(self.handle);
+    }
+}
+
+fn close(handle: Handle) {
+    // SAFETY: `handle` is a valid handle obtained from `CreateMutexW` that
+    // hasn't been closed yet at each of this function's call sites.
+    unsafe {
+        CloseHandle(handle);
+    }
+}